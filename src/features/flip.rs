@@ -0,0 +1,165 @@
+//! This module contains a [`Flip`] primitive which can be used to mirror a [`Table`]
+//! across its horizontal or vertical axis.
+//!
+//! This is distinct from [`Rotate`]: a flip reverses the order of rows or columns in place,
+//! while a rotate turns the table by 90/180 degrees. The two compose cleanly — a transpose
+//! followed by a horizontal flip yields the same result as a 90 degree rotation.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Flip, TableIteratorExt};
+//!
+//! let data = [[1, 2, 3], [4, 5, 6]];
+//!
+//! let table = data.table().with(Flip::Horizontal).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---+---+---+\n",
+//!         "| 2 | 1 | 0 |\n",
+//!         "+---+---+---+\n",
+//!         "| 3 | 2 | 1 |\n",
+//!         "+---+---+---+\n",
+//!         "| 6 | 5 | 4 |\n",
+//!         "+---+---+---+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+//! [`Rotate`]: crate::Rotate
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// Flip mirrors a [`Table`] across an axis, reversing the order of its columns or rows.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub enum Flip {
+    /// Mirror the table across the vertical axis, reversing the order of columns
+    /// left-to-right.
+    Horizontal,
+    /// Mirror the table across the horizontal axis, reversing the order of rows
+    /// top-to-bottom.
+    Vertical,
+}
+
+impl<R> TableOption<R> for Flip
+where
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        let records = table.get_records_mut();
+
+        match self {
+            Self::Horizontal => {
+                for col in 0..count_cols / 2 {
+                    records.swap_column(col, count_cols - col - 1);
+                }
+            }
+            Self::Vertical => {
+                for row in 0..count_rows / 2 {
+                    records.swap_row(row, count_rows - row - 1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::Builder;
+    use crate::features::rotate::transpose_square;
+    use crate::Rotate;
+
+    #[test]
+    fn flip_horizontal_on_rectangular_table() {
+        let table = Builder::from_iter(vec![vec!["1", "2", "3", "4"], vec!["5", "6", "7", "8"]])
+            .build()
+            .with(Flip::Horizontal)
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+---+---+---+---+\n",
+                "| 4 | 3 | 2 | 1 |\n",
+                "+---+---+---+---+\n",
+                "| 8 | 7 | 6 | 5 |\n",
+                "+---+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn flip_vertical_on_rectangular_table() {
+        let table = Builder::from_iter(vec![vec!["1", "2"], vec!["3", "4"], vec!["5", "6"]])
+            .build()
+            .with(Flip::Vertical)
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+---+---+\n",
+                "| 5 | 6 |\n",
+                "+---+---+\n",
+                "| 3 | 4 |\n",
+                "+---+---+\n",
+                "| 1 | 2 |\n",
+                "+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn odd_middle_row_is_untouched_by_vertical_flip() {
+        let table = Builder::from_iter(vec![vec!["1"], vec!["2"], vec!["3"]])
+            .build()
+            .with(Flip::Vertical)
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+---+\n", "| 3 |\n", "+---+\n", "| 2 |\n", "+---+\n", "| 1 |\n", "+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn odd_middle_column_is_untouched_by_horizontal_flip() {
+        let table = Builder::from_iter(vec![vec!["1", "2", "3"]])
+            .build()
+            .with(Flip::Horizontal)
+            .to_string();
+
+        assert_eq!(
+            table,
+            concat!("+---+---+---+\n", "| 3 | 2 | 1 |\n", "+---+---+---+",)
+        );
+    }
+
+    #[test]
+    fn transpose_then_horizontal_flip_matches_rotate_right() {
+        let data = vec![
+            vec!["1", "2", "3"],
+            vec!["4", "5", "6"],
+            vec!["7", "8", "9"],
+        ];
+
+        let mut transposed = Builder::from_iter(data.clone()).build();
+        transpose_square(transposed.get_records_mut(), 3);
+        let via_transpose_and_flip = transposed.with(Flip::Horizontal).to_string();
+
+        let via_rotate = Builder::from_iter(data).build().with(Rotate::Right).to_string();
+
+        assert_eq!(via_transpose_and_flip, via_rotate);
+    }
+}
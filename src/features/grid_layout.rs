@@ -0,0 +1,240 @@
+//! This module contains a [`GridLayout`] builder which packs a flat list of cells into a
+//! [`Table`] with as many columns as fit a given console width, similar to how `ls` lays out
+//! a directory listing.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::GridLayout;
+//!
+//! let items = ["one", "two", "three", "four", "five", "six"];
+//!
+//! let table = GridLayout::new(items).columns(3).build().to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+------+------+-------+\n",
+//!         "| one  | two  | three |\n",
+//!         "+------+------+-------+\n",
+//!         "| four | five | six   |\n",
+//!         "+------+------+-------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{builder::Builder, Table};
+
+/// The order in which cells are read off the flat input list and placed into the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridDirection {
+    /// Fill a row left-to-right before moving on to the next row (`ls -x`'s layout).
+    Across,
+    /// Fill a column top-to-bottom before moving on to the next column (plain `ls`'s default).
+    Down,
+}
+
+/// `GridLayout` arranges a flat list of cells into the widest [`Table`] that still fits a
+/// target console width, optionally forcing a fixed number of columns.
+///
+/// The column count is chosen by trying candidate counts from `1` upward, computing the
+/// per-column width each candidate would need, and keeping the largest candidate whose total
+/// width (including separators) still fits.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct GridLayout {
+    cells: Vec<String>,
+    width: usize,
+    direction: GridDirection,
+    columns: Option<usize>,
+    sep_width: usize,
+}
+
+impl GridLayout {
+    /// Build a [`GridLayout`] from a flat list of cells.
+    pub fn new<I, T>(cells: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: ToString,
+    {
+        Self {
+            cells: cells.into_iter().map(|cell| cell.to_string()).collect(),
+            width: 80,
+            direction: GridDirection::Across,
+            columns: None,
+            sep_width: 3,
+        }
+    }
+
+    /// Set the console width the layout must fit within. Default is `80`.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the fill direction. Default is [`GridDirection::Across`].
+    pub fn direction(mut self, direction: GridDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Force a specific number of columns, bypassing the width-fitting search.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set the width reserved for the separator between adjacent columns. Default is `3`.
+    pub fn separator_width(mut self, sep_width: usize) -> Self {
+        self.sep_width = sep_width;
+        self
+    }
+
+    /// Arrange the cells and build the resulting [`Table`].
+    pub fn build(self) -> Table {
+        if self.cells.is_empty() {
+            return Builder::from_iter(Vec::<Vec<String>>::new()).build();
+        }
+
+        let columns = self
+            .columns
+            .unwrap_or_else(|| self.fit_columns())
+            .max(1)
+            .min(self.cells.len());
+
+        let rows = self.arrange(columns);
+
+        Builder::from_iter(rows).build()
+    }
+
+    fn fit_columns(&self) -> usize {
+        // Total width isn't monotonic in the column count: adding a column can shrink every
+        // other column's row-span and shuffle which cells land where, so a wider candidate can
+        // end up narrower than one tried just before it. Check every candidate rather than
+        // stopping at the first one that doesn't fit.
+        let mut best = 1;
+
+        for candidate in 1..=self.cells.len() {
+            let total_width = self.total_width(candidate);
+            if total_width <= self.width {
+                best = candidate;
+            }
+        }
+
+        best
+    }
+
+    fn total_width(&self, columns: usize) -> usize {
+        let column_widths = self.column_widths(columns);
+        let separators = self.sep_width * column_widths.len().saturating_sub(1);
+        column_widths.into_iter().sum::<usize>() + separators
+    }
+
+    fn column_widths(&self, columns: usize) -> Vec<usize> {
+        let mut widths = vec![0; columns];
+        for (_, col, cell) in self.positions(columns) {
+            widths[col] = widths[col].max(cell.chars().count());
+        }
+
+        widths
+    }
+
+    fn arrange(&self, columns: usize) -> Vec<Vec<String>> {
+        let rows_count = Self::rows_for(self.cells.len(), columns);
+        let mut rows = vec![vec![String::new(); columns]; rows_count];
+
+        for (row, col, cell) in self.positions(columns) {
+            rows[row][col] = cell.to_string();
+        }
+
+        rows
+    }
+
+    fn positions(&self, columns: usize) -> impl Iterator<Item = (usize, usize, &str)> {
+        let rows_count = Self::rows_for(self.cells.len(), columns);
+        let direction = self.direction;
+
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            let (row, col) = match direction {
+                GridDirection::Across => (i / columns, i % columns),
+                GridDirection::Down => (i % rows_count, i / rows_count),
+            };
+
+            (row, col, cell.as_str())
+        })
+    }
+
+    fn rows_for(count: usize, columns: usize) -> usize {
+        (count + columns - 1) / columns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_columns_picks_largest_count_that_fits() {
+        let table = GridLayout::new(["1", "2", "3", "4", "5", "6"])
+            .width(10)
+            .build();
+
+        assert_eq!(table.shape(), (2, 3));
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "+---+---+---+\n",
+                "| 1 | 2 | 3 |\n",
+                "+---+---+---+\n",
+                "| 4 | 5 | 6 |\n",
+                "+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn fit_columns_is_not_fooled_by_a_non_monotonic_candidate() {
+        // total_width(3) = 25 (exceeds the 24 limit) but total_width(4) = 24 (fits): a search
+        // that breaks on the first non-fitting candidate would stop at 3 columns and never see
+        // that 4 columns fits after all. Regression test for the bug fixed in 48bd8fe.
+        let items = ["xx", "xxxxxx", "x", "x", "x", "xxxxxxxxxxx"];
+
+        let table = GridLayout::new(items).width(24).build();
+
+        assert_eq!(table.shape(), (2, 4));
+    }
+
+    #[test]
+    fn down_direction_fills_columns_before_rows() {
+        let table = GridLayout::new(["1", "2", "3", "4", "5", "6"])
+            .columns(3)
+            .direction(GridDirection::Down)
+            .build();
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "+---+---+---+\n",
+                "| 1 | 3 | 5 |\n",
+                "+---+---+---+\n",
+                "| 2 | 4 | 6 |\n",
+                "+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn separator_width_affects_how_many_columns_fit() {
+        let default_sep = GridLayout::new(["11", "22", "33", "44"]).width(9).build();
+        assert_eq!(default_sep.shape(), (2, 2));
+
+        let narrow_sep = GridLayout::new(["11", "22", "33", "44"])
+            .width(9)
+            .separator_width(1)
+            .build();
+        assert_eq!(narrow_sep.shape(), (2, 3));
+    }
+}
@@ -3,6 +3,10 @@
 //! It's also possible to transpose the table at the point of construction.
 //! See [`Builder::index`].
 //!
+//! For a square table [`Rotate::Left`]/[`Rotate::Right`] transpose the records in place. For a
+//! rectangular table they rebuild the records at the target shape directly, rather than padding
+//! the records out to a square and trimming them back down afterwards.
+//!
 //! # Example
 //!
 //! ```
@@ -29,7 +33,7 @@
 //! [`Table`]: crate::Table
 //! [`Builder::index`]: crate::builder::Builder::index
 
-use papergrid::records::{Records, Resizable};
+use papergrid::records::{Records, RecordsMut, Resizable};
 
 use crate::{Table, TableOption};
 
@@ -60,83 +64,28 @@ pub enum Rotate {
 
 impl<R> TableOption<R> for Rotate
 where
-    R: Records + Resizable,
+    R: Records + Resizable + RecordsMut<String>,
 {
     fn change(&mut self, table: &mut Table<R>) {
         let (count_rows, count_cols) = table.shape();
-        let records = table.get_records_mut();
-        match self {
-            Self::Left => {
-                {
-                    let n = std::cmp::max(count_rows, count_cols);
-                    for _ in count_rows..n {
-                        records.push_row();
-                    }
-
-                    for _ in count_cols..n {
-                        records.push_column();
-                    }
-                }
-
-                for col in 0..count_cols {
-                    for row in col..count_rows {
-                        records.swap((col, row), (row, col));
-                    }
-                }
 
-                for row in 0..count_cols / 2 {
-                    records.swap_row(row, count_cols - row - 1);
-                }
-
-                {
-                    let n = std::cmp::max(count_rows, count_cols);
-                    for (shift, row) in (count_rows..n).enumerate() {
-                        let row = row - shift;
-                        records.remove_column(row);
-                    }
-
-                    for (shift, col) in (count_cols..n).enumerate() {
-                        let col = col - shift;
-                        records.remove_row(col);
-                    }
-                }
+        match self {
+            Self::Left if count_rows == count_cols => {
+                transpose_square(table.get_records_mut(), count_rows);
+                reverse_rows(table.get_records_mut(), count_rows);
             }
-            Self::Right => {
-                {
-                    let n = std::cmp::max(count_rows, count_cols);
-                    for _ in count_rows..n {
-                        records.push_row();
-                    }
-
-                    for _ in count_cols..n {
-                        records.push_column();
-                    }
-                }
-
-                for col in 0..count_cols {
-                    for row in col..count_rows {
-                        records.swap((col, row), (row, col));
-                    }
-                }
-
-                for col in 0..count_rows / 2 {
-                    records.swap_column(col, count_rows - col - 1);
-                }
-
-                {
-                    let n = std::cmp::max(count_rows, count_cols);
-                    for (shift, row) in (count_rows..n).enumerate() {
-                        let row = row - shift;
-                        records.remove_column(row);
-                    }
-
-                    for (shift, col) in (count_cols..n).enumerate() {
-                        let col = col - shift;
-                        records.remove_row(col);
-                    }
-                }
+            Self::Right if count_rows == count_cols => {
+                transpose_square(table.get_records_mut(), count_rows);
+                reverse_columns(table.get_records_mut(), count_cols);
             }
+            Self::Left => rebuild(table, count_rows, count_cols, |row, col| {
+                (col, count_cols - 1 - row)
+            }),
+            Self::Right => rebuild(table, count_rows, count_cols, |row, col| {
+                (count_rows - 1 - col, row)
+            }),
             Self::Bottom => {
+                let records = table.get_records_mut();
                 for row in 0..count_rows / 2 {
                     for col in 0..count_cols {
                         let last_row = count_rows - row - 1;
@@ -148,3 +97,123 @@ where
         }
     }
 }
+
+/// Transpose a square `n x n` block of records in place, without any padding or trimming.
+pub(crate) fn transpose_square<R: Resizable>(records: &mut R, n: usize) {
+    for col in 0..n {
+        for row in col..n {
+            records.swap((col, row), (row, col));
+        }
+    }
+}
+
+fn reverse_rows<R: Resizable>(records: &mut R, count_rows: usize) {
+    for row in 0..count_rows / 2 {
+        records.swap_row(row, count_rows - row - 1);
+    }
+}
+
+fn reverse_columns<R: Resizable>(records: &mut R, count_cols: usize) {
+    for col in 0..count_cols / 2 {
+        records.swap_column(col, count_cols - col - 1);
+    }
+}
+
+/// Rotate a rectangular (`count_rows != count_cols`) table by emptying its records and
+/// regrowing them directly to the target `count_cols x count_rows` shape, rather than padding
+/// the records out to an `n x n` square and trimming them back down afterwards. This avoids allocating and freeing
+/// up to `n^2` cells for a table that is far from square.
+///
+/// `map` takes a destination `(row, col)` in the new `count_cols x count_rows` shape and
+/// returns the source `(row, col)` in the original table that its content comes from.
+fn rebuild<R>(
+    table: &mut Table<R>,
+    count_rows: usize,
+    count_cols: usize,
+    map: impl Fn(usize, usize) -> (usize, usize),
+) where
+    R: Records + Resizable + RecordsMut<String>,
+{
+    let source: Vec<Vec<String>> = (0..count_rows)
+        .map(|row| {
+            (0..count_cols)
+                .map(|col| table.get_records().get_text((row, col)).to_string())
+                .collect()
+        })
+        .collect();
+
+    let records = table.get_records_mut();
+
+    // Clear both axes explicitly rather than relying on `remove_row`/`remove_column` to reset
+    // the other axis' count as a side effect.
+    for _ in 0..count_cols {
+        records.remove_column(0);
+    }
+
+    while records.count_rows() > 0 {
+        records.remove_row(0);
+    }
+
+    for _ in 0..count_cols {
+        records.push_row();
+    }
+
+    for _ in 0..count_rows {
+        records.push_column();
+    }
+
+    for row in 0..count_cols {
+        for col in 0..count_rows {
+            let (src_row, src_col) = map(row, col);
+            records.set((row, col), source[src_row][src_col].clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::Builder;
+
+    fn rectangular() -> Table {
+        Builder::from_iter(vec![vec!["1", "2", "3"], vec!["4", "5", "6"]]).build()
+    }
+
+    #[test]
+    fn rotate_left_on_rectangular_table() {
+        let rotated = rectangular().with(Rotate::Left);
+
+        assert_eq!(rotated.shape(), (3, 2));
+        assert_eq!(
+            rotated.to_string(),
+            concat!(
+                "+---+---+\n",
+                "| 3 | 6 |\n",
+                "+---+---+\n",
+                "| 2 | 5 |\n",
+                "+---+---+\n",
+                "| 1 | 4 |\n",
+                "+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn rotate_right_on_rectangular_table() {
+        let rotated = rectangular().with(Rotate::Right);
+
+        assert_eq!(rotated.shape(), (3, 2));
+        assert_eq!(
+            rotated.to_string(),
+            concat!(
+                "+---+---+\n",
+                "| 4 | 1 |\n",
+                "+---+---+\n",
+                "| 5 | 2 |\n",
+                "+---+---+\n",
+                "| 6 | 3 |\n",
+                "+---+---+",
+            )
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! This module contains a [`Shift`] primitive which can be used to cyclically rotate
+//! the cells of a single row or column of a [`Table`] by `N` positions, wrapping at the edges.
+//!
+//! Unlike [`Rotate`] \(which rotates the whole table by 90/180 degrees\) [`Shift`] only moves
+//! the cells of one row or one column, leaving the rest of the table untouched.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Shift, TableIteratorExt};
+//!
+//! let data = [[1, 2, 3], [4, 5, 6]];
+//!
+//! let table = data.table().with(Shift::Row(0, 1)).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---+---+---+\n",
+//!         "| 1 | 2 | 0 |\n",
+//!         "+---+---+---+\n",
+//!         "| 1 | 2 | 3 |\n",
+//!         "+---+---+---+\n",
+//!         "| 4 | 5 | 6 |\n",
+//!         "+---+---+---+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+//! [`Rotate`]: crate::Rotate
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{Table, TableOption};
+
+/// Shift cyclically moves the cells of a single row or column by `N` positions, wrapping
+/// around at the edges.
+///
+/// A positive shift moves cells towards the start of the line (a "left"/"up" scroll); a
+/// negative shift moves them towards the end (a "right"/"down" scroll). Shifting by a
+/// multiple of the line's length, or shifting an empty line, is a no-op.
+#[derive(Debug)]
+pub enum Shift {
+    /// Shift the row at the given index by `N` cells.
+    Row(usize, isize),
+    /// Shift the column at the given index by `N` cells.
+    Column(usize, isize),
+}
+
+impl<R> TableOption<R> for Shift
+where
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_cols) = table.shape();
+        let records = table.get_records_mut();
+
+        match *self {
+            Self::Row(row, n) => shift_row(records, row, count_cols, n),
+            Self::Column(col, n) => shift_column(records, col, count_rows, n),
+        }
+    }
+}
+
+fn shift_row<R: Resizable>(records: &mut R, row: usize, count_cols: usize, n: isize) {
+    let k = normalize_shift(n, count_cols);
+    if k == 0 {
+        return;
+    }
+
+    reverse_row(records, row, 0, k);
+    reverse_row(records, row, k, count_cols);
+    reverse_row(records, row, 0, count_cols);
+}
+
+fn shift_column<R: Resizable>(records: &mut R, col: usize, count_rows: usize, n: isize) {
+    let k = normalize_shift(n, count_rows);
+    if k == 0 {
+        return;
+    }
+
+    reverse_column(records, col, 0, k);
+    reverse_column(records, col, k, count_rows);
+    reverse_column(records, col, 0, count_rows);
+}
+
+fn reverse_row<R: Resizable>(records: &mut R, row: usize, start: usize, end: usize) {
+    let mut i = start;
+    let mut j = end - 1;
+    while i < j {
+        records.swap((row, i), (row, j));
+        i += 1;
+        j -= 1;
+    }
+}
+
+fn reverse_column<R: Resizable>(records: &mut R, col: usize, start: usize, end: usize) {
+    let mut i = start;
+    let mut j = end - 1;
+    while i < j {
+        records.swap((i, col), (j, col));
+        i += 1;
+        j -= 1;
+    }
+}
+
+fn normalize_shift(n: isize, len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+
+    n.rem_euclid(len as isize) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TableIteratorExt;
+
+    fn data() -> [[i32; 3]; 2] {
+        [[1, 2, 3], [4, 5, 6]]
+    }
+
+    #[test]
+    fn shift_row() {
+        let table = data().table().with(Shift::Row(0, 1)).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+---+---+---+\n",
+                "| 1 | 2 | 0 |\n",
+                "+---+---+---+\n",
+                "| 1 | 2 | 3 |\n",
+                "+---+---+---+\n",
+                "| 4 | 5 | 6 |\n",
+                "+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn shift_column() {
+        let table = data().table().with(Shift::Column(0, 1)).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "+---+---+---+\n",
+                "| 1 | 1 | 2 |\n",
+                "+---+---+---+\n",
+                "| 4 | 2 | 3 |\n",
+                "+---+---+---+\n",
+                "| 0 | 5 | 6 |\n",
+                "+---+---+---+",
+            )
+        );
+    }
+
+    #[test]
+    fn negative_shift_goes_the_other_way() {
+        let left = data().table().with(Shift::Row(0, 1)).to_string();
+        let right = data().table().with(Shift::Row(0, -2)).to_string();
+
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn shift_by_a_multiple_of_the_length_is_a_no_op() {
+        let original = data().table().to_string();
+        let shifted = data().table().with(Shift::Row(0, 3)).to_string();
+
+        assert_eq!(original, shifted);
+    }
+
+    #[test]
+    fn shift_of_an_empty_line_is_a_no_op() {
+        assert_eq!(normalize_shift(1, 0), 0);
+        assert_eq!(normalize_shift(-7, 0), 0);
+    }
+
+    #[test]
+    fn normalize_shift_wraps_negative_values() {
+        assert_eq!(normalize_shift(-1, 3), 2);
+        assert_eq!(normalize_shift(-2, 3), 1);
+        assert_eq!(normalize_shift(3, 3), 0);
+    }
+}